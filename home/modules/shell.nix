@@ -261,6 +261,7 @@ in {
 
     # Secrets management
     secrets = "~/.dotfiles/scripts/secrets-edit";
+    secrets-rekey = "~/.dotfiles/scripts/secrets-rekey";
 
     # Promote live Codex client model/reasoning choices into dotfiles.
     codex-save-prefs = "~/.dotfiles/scripts/codex-preferences save";